@@ -2,6 +2,7 @@ use super::{
     error::Error,
     ftdi::{list_ftdi_devices, FtdiDevice, FtdiError},
 };
+use crc32fast::Hasher;
 use serial2::SerialPort;
 use std::{
     collections::VecDeque,
@@ -11,6 +12,7 @@ use std::{
     time::{Duration, Instant},
 };
 
+#[derive(Clone, Copy)]
 pub enum DataType {
     Command,
     Response,
@@ -67,6 +69,460 @@ const POLL_TIMEOUT: Duration = Duration::from_millis(1);
 const READ_TIMEOUT: Duration = Duration::from_secs(5);
 const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
 
+const RESYNC_MAX_DISCARDED_BYTES: usize = 4096;
+
+pub(crate) fn crc32(chunks: &[&[u8]]) -> u32 {
+    let mut hasher = Hasher::new();
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    hasher.finalize()
+}
+
+fn decode_token(header: &[u8; 4]) -> Result<(bool, bool), ()> {
+    match &header[0..3] {
+        b"CMP" => Ok((false, false)),
+        b"PKT" => Ok((true, false)),
+        b"ERR" => Ok((false, true)),
+        _ => Err(()),
+    }
+}
+
+const DISCONNECT_MARKER: &str = "device disconnected:";
+
+/// A physically removed USB-serial/FTDI device doesn't surface as one of the well-kinded
+/// cross-platform disconnect errors below; the driver instead reports a platform-specific raw OS
+/// error code that `std::io::Error` only exposes as `ErrorKind::Other`, so it needs checking per
+/// platform explicitly - the same numeric code means something else entirely on Windows vs. POSIX.
+#[cfg(unix)]
+fn is_device_removed_error(error: &std::io::Error) -> bool {
+    const ENXIO: i32 = 6;
+    const ENODEV: i32 = 19;
+    matches!(error.raw_os_error(), Some(ENXIO) | Some(ENODEV))
+}
+
+#[cfg(windows)]
+fn is_device_removed_error(error: &std::io::Error) -> bool {
+    const ERROR_GEN_FAILURE: i32 = 31;
+    const ERROR_DEVICE_NOT_CONNECTED: i32 = 1167;
+    matches!(
+        error.raw_os_error(),
+        Some(ERROR_GEN_FAILURE) | Some(ERROR_DEVICE_NOT_CONNECTED)
+    )
+}
+
+#[cfg(not(any(unix, windows)))]
+fn is_device_removed_error(_error: &std::io::Error) -> bool {
+    false
+}
+
+/// Wraps an I/O error into the link's `Error` type, tagging disconnect-class kinds (broken pipe,
+/// connection reset, device unplugged) so `Link::set_reconnect` can tell them apart from transient
+/// or protocol-level failures that shouldn't trigger a reconnect attempt.
+fn classify_io_error(error: std::io::Error) -> Error {
+    let disconnected = matches!(
+        error.kind(),
+        ErrorKind::BrokenPipe
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::UnexpectedEof
+    ) || is_device_removed_error(&error);
+    if disconnected {
+        Error::new(format!("{DISCONNECT_MARKER} {error}").as_str())
+    } else {
+        Error::new(error.to_string().as_str())
+    }
+}
+
+fn is_disconnect_error(error: &Error) -> bool {
+    error.to_string().contains(DISCONNECT_MARKER)
+}
+
+/// A decoded `CMP`/`PKT`/`ERR` frame, still owned by the parser that produced it.
+struct Frame {
+    id: u8,
+    packet_token: bool,
+    error: bool,
+    data: Vec<u8>,
+}
+
+enum FrameState {
+    WaitHeader,
+    WaitLength {
+        id: u8,
+        packet_token: bool,
+        error: bool,
+    },
+    WaitPayload {
+        id: u8,
+        packet_token: bool,
+        error: bool,
+        length: usize,
+    },
+    WaitCrc {
+        id: u8,
+        packet_token: bool,
+        error: bool,
+        data: Vec<u8>,
+    },
+}
+
+/// Resumable parser for the serial/FTDI `CMP`/`PKT`/`ERR` wire format. Bytes returned by
+/// `Backend::read` are fed into an internal ring buffer and the state machine (`WaitHeader` ->
+/// `WaitLength` -> `WaitPayload` -> optionally `WaitCrc`) advances as far as currently buffered
+/// bytes allow, yielding a complete frame only once enough data has arrived. This lets a caller
+/// pump the link at its own cadence instead of blocking mid-frame, mirroring the incremental
+/// "consume bytes, yield packets as they complete" model used by streaming parsers like `ublox`.
+struct FrameParser {
+    buffer: VecDeque<u8>,
+    state: FrameState,
+    discarded: usize,
+    resync_started: Option<Instant>,
+}
+
+impl FrameParser {
+    fn new() -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            state: FrameState::WaitHeader,
+            discarded: 0,
+            resync_started: None,
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend(bytes);
+    }
+
+    fn poll(&mut self, resync: bool, crc: bool) -> Result<Option<Frame>, Error> {
+        loop {
+            match std::mem::replace(&mut self.state, FrameState::WaitHeader) {
+                FrameState::WaitHeader => {
+                    if self.buffer.len() < 4 {
+                        return Ok(None);
+                    }
+                    let header = [self.buffer[0], self.buffer[1], self.buffer[2], self.buffer[3]];
+                    match decode_token(&header) {
+                        Ok((packet_token, error)) => {
+                            self.buffer.drain(..4);
+                            self.discarded = 0;
+                            self.resync_started = None;
+                            self.state = FrameState::WaitLength {
+                                id: header[3],
+                                packet_token,
+                                error,
+                            };
+                        }
+                        Err(()) if resync => {
+                            self.buffer.pop_front();
+                            self.discarded += 1;
+                            let started = *self.resync_started.get_or_insert_with(Instant::now);
+                            if self.discarded >= RESYNC_MAX_DISCARDED_BYTES
+                                || started.elapsed() > RESET_TIMEOUT
+                            {
+                                return Err(Error::new(
+                                    format!(
+                                        "Couldn't resynchronize frame, discarded {} bytes",
+                                        self.discarded
+                                    )
+                                    .as_str(),
+                                ));
+                            }
+                        }
+                        Err(()) => return Err(Error::new("Unknown response token")),
+                    }
+                }
+                FrameState::WaitLength {
+                    id,
+                    packet_token,
+                    error,
+                } => {
+                    if self.buffer.len() < 4 {
+                        self.state = FrameState::WaitLength {
+                            id,
+                            packet_token,
+                            error,
+                        };
+                        return Ok(None);
+                    }
+                    let length = u32::from_be_bytes([
+                        self.buffer[0],
+                        self.buffer[1],
+                        self.buffer[2],
+                        self.buffer[3],
+                    ]) as usize;
+                    self.buffer.drain(..4);
+                    self.state = FrameState::WaitPayload {
+                        id,
+                        packet_token,
+                        error,
+                        length,
+                    };
+                }
+                FrameState::WaitPayload {
+                    id,
+                    packet_token,
+                    error,
+                    length,
+                } => {
+                    if self.buffer.len() < length {
+                        self.state = FrameState::WaitPayload {
+                            id,
+                            packet_token,
+                            error,
+                            length,
+                        };
+                        return Ok(None);
+                    }
+                    let data: Vec<u8> = self.buffer.drain(..length).collect();
+                    if crc {
+                        self.state = FrameState::WaitCrc {
+                            id,
+                            packet_token,
+                            error,
+                            data,
+                        };
+                    } else {
+                        self.state = FrameState::WaitHeader;
+                        return Ok(Some(Frame {
+                            id,
+                            packet_token,
+                            error,
+                            data,
+                        }));
+                    }
+                }
+                FrameState::WaitCrc {
+                    id,
+                    packet_token,
+                    error,
+                    data,
+                } => {
+                    if self.buffer.len() < 4 {
+                        self.state = FrameState::WaitCrc {
+                            id,
+                            packet_token,
+                            error,
+                            data,
+                        };
+                        return Ok(None);
+                    }
+                    let received = u32::from_be_bytes([
+                        self.buffer[0],
+                        self.buffer[1],
+                        self.buffer[2],
+                        self.buffer[3],
+                    ]);
+                    self.buffer.drain(..4);
+                    self.state = FrameState::WaitHeader;
+                    if received != crc32(&[&[id], &data]) {
+                        return Err(Error::new("CRC32 checksum mismatch"));
+                    }
+                    return Ok(Some(Frame {
+                        id,
+                        packet_token,
+                        error,
+                        data,
+                    }));
+                }
+            }
+        }
+    }
+}
+
+enum TcpFrameState {
+    WaitDataType,
+    WaitInfo {
+        data_type: DataType,
+    },
+    WaitLength {
+        data_type: DataType,
+        info: Vec<u8>,
+    },
+    WaitPayload {
+        data_type: DataType,
+        info: Vec<u8>,
+        length: usize,
+    },
+    WaitCrc {
+        data_type: DataType,
+        info: Vec<u8>,
+        data: Vec<u8>,
+    },
+}
+
+/// Resumable parser for the `DataType`-prefixed TCP wire format, mirroring `FrameParser` but for
+/// the remote link's framing (a 4-byte `DataType` tag followed by a type-specific info field).
+struct TcpFrameParser {
+    buffer: VecDeque<u8>,
+    state: TcpFrameState,
+    discarded: usize,
+    resync_started: Option<Instant>,
+}
+
+impl TcpFrameParser {
+    fn new() -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            state: TcpFrameState::WaitDataType,
+            discarded: 0,
+            resync_started: None,
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend(bytes);
+    }
+
+    fn info_len(data_type: DataType) -> usize {
+        match data_type {
+            DataType::Response => 2,
+            DataType::Packet => 1,
+            _ => 0,
+        }
+    }
+
+    fn build_frame(data_type: DataType, info: &[u8], data: Vec<u8>) -> Frame {
+        match data_type {
+            DataType::Response => Frame {
+                id: info[0],
+                packet_token: false,
+                error: info[1] != 0,
+                data,
+            },
+            _ => Frame {
+                id: info[0],
+                packet_token: true,
+                error: false,
+                data,
+            },
+        }
+    }
+
+    fn poll(&mut self, resync: bool, crc: bool) -> Result<Option<Frame>, Error> {
+        loop {
+            match std::mem::replace(&mut self.state, TcpFrameState::WaitDataType) {
+                TcpFrameState::WaitDataType => {
+                    if self.buffer.len() < 4 {
+                        return Ok(None);
+                    }
+                    let header = [self.buffer[0], self.buffer[1], self.buffer[2], self.buffer[3]];
+                    match u32::from_be_bytes(header).try_into() {
+                        Ok(data_type) => {
+                            self.buffer.drain(..4);
+                            self.discarded = 0;
+                            self.resync_started = None;
+                            self.state = match data_type {
+                                DataType::KeepAlive => TcpFrameState::WaitDataType,
+                                DataType::Command => {
+                                    return Err(Error::new("Unexpected payload data type received"))
+                                }
+                                _ => TcpFrameState::WaitInfo { data_type },
+                            };
+                        }
+                        Err(_) if resync => {
+                            self.buffer.pop_front();
+                            self.discarded += 1;
+                            let started = *self.resync_started.get_or_insert_with(Instant::now);
+                            if self.discarded >= RESYNC_MAX_DISCARDED_BYTES
+                                || started.elapsed() > RESET_TIMEOUT
+                            {
+                                return Err(Error::new(
+                                    format!(
+                                        "Couldn't resynchronize frame, discarded {} bytes",
+                                        self.discarded
+                                    )
+                                    .as_str(),
+                                ));
+                            }
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+                TcpFrameState::WaitInfo { data_type } => {
+                    let length = Self::info_len(data_type);
+                    if self.buffer.len() < length {
+                        self.state = TcpFrameState::WaitInfo { data_type };
+                        return Ok(None);
+                    }
+                    let info: Vec<u8> = self.buffer.drain(..length).collect();
+                    self.state = TcpFrameState::WaitLength { data_type, info };
+                }
+                TcpFrameState::WaitLength { data_type, info } => {
+                    if self.buffer.len() < 4 {
+                        self.state = TcpFrameState::WaitLength { data_type, info };
+                        return Ok(None);
+                    }
+                    let length = u32::from_be_bytes([
+                        self.buffer[0],
+                        self.buffer[1],
+                        self.buffer[2],
+                        self.buffer[3],
+                    ]) as usize;
+                    self.buffer.drain(..4);
+                    self.state = TcpFrameState::WaitPayload {
+                        data_type,
+                        info,
+                        length,
+                    };
+                }
+                TcpFrameState::WaitPayload {
+                    data_type,
+                    info,
+                    length,
+                } => {
+                    if self.buffer.len() < length {
+                        self.state = TcpFrameState::WaitPayload {
+                            data_type,
+                            info,
+                            length,
+                        };
+                        return Ok(None);
+                    }
+                    let data: Vec<u8> = self.buffer.drain(..length).collect();
+                    if crc {
+                        self.state = TcpFrameState::WaitCrc {
+                            data_type,
+                            info,
+                            data,
+                        };
+                    } else {
+                        self.state = TcpFrameState::WaitDataType;
+                        return Ok(Some(Self::build_frame(data_type, &info, data)));
+                    }
+                }
+                TcpFrameState::WaitCrc {
+                    data_type,
+                    info,
+                    data,
+                } => {
+                    if self.buffer.len() < 4 {
+                        self.state = TcpFrameState::WaitCrc {
+                            data_type,
+                            info,
+                            data,
+                        };
+                        return Ok(None);
+                    }
+                    let received = u32::from_be_bytes([
+                        self.buffer[0],
+                        self.buffer[1],
+                        self.buffer[2],
+                        self.buffer[3],
+                    ]);
+                    self.buffer.drain(..4);
+                    self.state = TcpFrameState::WaitDataType;
+                    if received != crc32(&[&[info[0]], &data]) {
+                        return Err(Error::new("CRC32 checksum mismatch"));
+                    }
+                    return Ok(Some(Self::build_frame(data_type, &info, data)));
+                }
+            }
+        }
+    }
+}
+
 pub trait Backend {
     fn reset(&mut self) -> Result<(), Error>;
 
@@ -100,96 +556,65 @@ pub trait Backend {
         }
     }
 
-    fn try_read(&mut self, buffer: &mut [u8], block: bool) -> Result<Option<()>, Error> {
-        let mut position = 0;
-        let length = buffer.len();
-        let timeout = Instant::now();
-        while position < length {
-            match self.read(&mut buffer[position..length]) {
-                Ok(0) => return Err(Error::new("Unexpected end of stream data")),
-                Ok(bytes) => position += bytes,
-                Err(error) => match error.kind() {
-                    ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock => {
-                        if !block && position == 0 {
-                            return Ok(None);
-                        }
-                    }
-                    _ => return Err(error.into()),
-                },
-            }
-            if timeout.elapsed() > READ_TIMEOUT {
-                return Err(Error::new("Read timeout"));
-            }
+    fn send_command(&mut self, command: &Command, crc: bool) -> Result<(), Error> {
+        self.write(b"CMD").map_err(classify_io_error)?;
+        self.write(&command.id.to_be_bytes())
+            .map_err(classify_io_error)?;
+
+        self.write(&command.args[0].to_be_bytes())
+            .map_err(classify_io_error)?;
+        self.write(&command.args[1].to_be_bytes())
+            .map_err(classify_io_error)?;
+
+        self.write(&command.data).map_err(classify_io_error)?;
+
+        if crc {
+            let checksum = crc32(&[
+                &command.id.to_be_bytes(),
+                &command.args[0].to_be_bytes(),
+                &command.args[1].to_be_bytes(),
+                &command.data,
+            ]);
+            self.write(&checksum.to_be_bytes())
+                .map_err(classify_io_error)?;
         }
-        Ok(Some(()))
-    }
 
-    fn try_read_header(&mut self, block: bool) -> Result<Option<[u8; 4]>, Error> {
-        let mut header = [0u8; 4];
-        Ok(self.try_read(&mut header, block)?.map(|_| header))
-    }
+        self.flush().map_err(classify_io_error)?;
 
-    fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
-        match self.try_read(buffer, true)? {
-            Some(()) => Ok(()),
-            None => Err(Error::new("Unexpected end of data")),
-        }
+        Ok(())
     }
 
-    fn send_command(&mut self, command: &Command) -> Result<(), Error> {
-        self.write(b"CMD")?;
-        self.write(&command.id.to_be_bytes())?;
-
-        self.write(&command.args[0].to_be_bytes())?;
-        self.write(&command.args[1].to_be_bytes())?;
-
-        self.write(&command.data)?;
-
-        self.flush()?;
-
-        Ok(())
+    /// Drains whatever bytes are currently available without blocking. Backends have their
+    /// underlying read timeout set to `POLL_TIMEOUT`, so this returns as soon as the device has
+    /// gone quiet rather than waiting for a full frame to arrive.
+    fn poll_available(&mut self) -> Result<Vec<u8>, Error> {
+        let mut collected = Vec::new();
+        loop {
+            let mut chunk = [0u8; 512];
+            match self.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(bytes) => collected.extend_from_slice(&chunk[..bytes]),
+                Err(error) => match error.kind() {
+                    ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock => break,
+                    _ => return Err(classify_io_error(error)),
+                },
+            }
+        }
+        Ok(collected)
     }
 
     fn process_incoming_data(
         &mut self,
         data_type: DataType,
         packets: &mut VecDeque<Packet>,
-    ) -> Result<Option<Response>, Error> {
-        let block = matches!(data_type, DataType::Response);
-
-        while let Some(header) = self.try_read_header(block)? {
-            let (packet_token, error) = (match &header[0..3] {
-                b"CMP" => Ok((false, false)),
-                b"PKT" => Ok((true, false)),
-                b"ERR" => Ok((false, true)),
-                _ => Err(Error::new("Unknown response token")),
-            })?;
-            let id = header[3];
-
-            let mut buffer = [0u8; 4];
-
-            self.read_exact(&mut buffer)?;
-            let length = u32::from_be_bytes(buffer) as usize;
-
-            let mut data = vec![0u8; length];
-            self.read_exact(&mut data)?;
-
-            if packet_token {
-                packets.push_back(Packet { id, data });
-                if matches!(data_type, DataType::Packet) {
-                    break;
-                }
-            } else {
-                return Ok(Some(Response { id, error, data }));
-            }
-        }
-
-        Ok(None)
-    }
+        resync: bool,
+        crc: bool,
+    ) -> Result<Option<Response>, Error>;
 }
 
 pub struct SerialBackend {
     device: SerialPort,
+    parser: FrameParser,
 }
 
 impl Backend for SerialBackend {
@@ -235,17 +660,52 @@ impl Backend for SerialBackend {
     fn flush(&mut self) -> std::io::Result<()> {
         self.device.flush()
     }
+
+    fn process_incoming_data(
+        &mut self,
+        data_type: DataType,
+        packets: &mut VecDeque<Packet>,
+        resync: bool,
+        crc: bool,
+    ) -> Result<Option<Response>, Error> {
+        let bytes = self.poll_available()?;
+        self.parser.feed(&bytes);
+
+        while let Some(frame) = self.parser.poll(resync, crc)? {
+            if frame.packet_token {
+                packets.push_back(Packet {
+                    id: frame.id,
+                    data: frame.data,
+                });
+                if matches!(data_type, DataType::Packet) {
+                    break;
+                }
+            } else {
+                return Ok(Some(Response {
+                    id: frame.id,
+                    error: frame.error,
+                    data: frame.data,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 fn new_serial_backend(port: &str) -> std::io::Result<SerialBackend> {
     let mut serial = SerialPort::open(port, 115_200)?;
     serial.set_read_timeout(POLL_TIMEOUT)?;
     serial.set_write_timeout(WRITE_TIMEOUT)?;
-    Ok(SerialBackend { device: serial })
+    Ok(SerialBackend {
+        device: serial,
+        parser: FrameParser::new(),
+    })
 }
 
 struct FtdiBackend {
     device: FtdiDevice,
+    parser: FrameParser,
 }
 
 impl Backend for FtdiBackend {
@@ -291,11 +751,43 @@ impl Backend for FtdiBackend {
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
+
+    fn process_incoming_data(
+        &mut self,
+        data_type: DataType,
+        packets: &mut VecDeque<Packet>,
+        resync: bool,
+        crc: bool,
+    ) -> Result<Option<Response>, Error> {
+        let bytes = self.poll_available()?;
+        self.parser.feed(&bytes);
+
+        while let Some(frame) = self.parser.poll(resync, crc)? {
+            if frame.packet_token {
+                packets.push_back(Packet {
+                    id: frame.id,
+                    data: frame.data,
+                });
+                if matches!(data_type, DataType::Packet) {
+                    break;
+                }
+            } else {
+                return Ok(Some(Response {
+                    id: frame.id,
+                    error: frame.error,
+                    data: frame.data,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 fn new_ftdi_backend(port: &str) -> Result<FtdiBackend, FtdiError> {
     Ok(FtdiBackend {
         device: FtdiDevice::open(port, POLL_TIMEOUT, WRITE_TIMEOUT)?,
+        parser: FrameParser::new(),
     })
 }
 
@@ -303,6 +795,7 @@ struct TcpBackend {
     stream: TcpStream,
     reader: BufReader<TcpStream>,
     writer: BufWriter<TcpStream>,
+    parser: TcpFrameParser,
 }
 
 impl Backend for TcpBackend {
@@ -326,19 +819,35 @@ impl Backend for TcpBackend {
         self.writer.flush()
     }
 
-    fn send_command(&mut self, command: &Command) -> Result<(), Error> {
+    fn send_command(&mut self, command: &Command, crc: bool) -> Result<(), Error> {
         let payload_data_type: u32 = DataType::Command.into();
-        self.write(&payload_data_type.to_be_bytes())?;
+        self.write(&payload_data_type.to_be_bytes())
+            .map_err(classify_io_error)?;
 
-        self.write(&command.id.to_be_bytes())?;
-        self.write(&command.args[0].to_be_bytes())?;
-        self.write(&command.args[1].to_be_bytes())?;
+        self.write(&command.id.to_be_bytes())
+            .map_err(classify_io_error)?;
+        self.write(&command.args[0].to_be_bytes())
+            .map_err(classify_io_error)?;
+        self.write(&command.args[1].to_be_bytes())
+            .map_err(classify_io_error)?;
 
         let command_data_length = command.data.len() as u32;
-        self.write(&command_data_length.to_be_bytes())?;
-        self.write(&command.data)?;
+        self.write(&command_data_length.to_be_bytes())
+            .map_err(classify_io_error)?;
+        self.write(&command.data).map_err(classify_io_error)?;
+
+        if crc {
+            let checksum = crc32(&[
+                &command.id.to_be_bytes(),
+                &command.args[0].to_be_bytes(),
+                &command.args[1].to_be_bytes(),
+                &command.data,
+            ]);
+            self.write(&checksum.to_be_bytes())
+                .map_err(classify_io_error)?;
+        }
 
-        self.flush()?;
+        self.flush().map_err(classify_io_error)?;
 
         Ok(())
     }
@@ -347,49 +856,28 @@ impl Backend for TcpBackend {
         &mut self,
         data_type: DataType,
         packets: &mut VecDeque<Packet>,
+        resync: bool,
+        crc: bool,
     ) -> Result<Option<Response>, Error> {
-        let block = matches!(data_type, DataType::Response);
-        while let Some(header) = self.try_read_header(block)? {
-            let payload_data_type: DataType = u32::from_be_bytes(header).try_into()?;
-            let mut buffer = [0u8; 4];
-            match payload_data_type {
-                DataType::Response => {
-                    let mut response_info = vec![0u8; 2];
-                    self.read_exact(&mut response_info)?;
-
-                    self.read_exact(&mut buffer)?;
-                    let response_data_length = u32::from_be_bytes(buffer) as usize;
-
-                    let mut data = vec![0u8; response_data_length];
-                    self.read_exact(&mut data)?;
-
-                    return Ok(Some(Response {
-                        id: response_info[0],
-                        error: response_info[1] != 0,
-                        data,
-                    }));
-                }
-                DataType::Packet => {
-                    let mut packet_info = vec![0u8; 1];
-                    self.read_exact(&mut packet_info)?;
-
-                    self.read_exact(&mut buffer)?;
-                    let packet_data_length = u32::from_be_bytes(buffer) as usize;
-
-                    let mut data = vec![0u8; packet_data_length];
-                    self.read_exact(&mut data)?;
-
-                    packets.push_back(Packet {
-                        id: packet_info[0],
-                        data,
-                    });
-                    if matches!(data_type, DataType::Packet) {
-                        break;
-                    }
+        let bytes = self.poll_available()?;
+        self.parser.feed(&bytes);
+
+        while let Some(frame) = self.parser.poll(resync, crc)? {
+            if frame.packet_token {
+                packets.push_back(Packet {
+                    id: frame.id,
+                    data: frame.data,
+                });
+                if matches!(data_type, DataType::Packet) {
+                    break;
                 }
-                DataType::KeepAlive => {}
-                _ => return Err(Error::new("Unexpected payload data type received")),
-            };
+            } else {
+                return Ok(Some(Response {
+                    id: frame.id,
+                    error: frame.error,
+                    data: frame.data,
+                }));
+            }
         }
 
         Ok(None)
@@ -415,10 +903,11 @@ fn new_tcp_backend(address: &str) -> Result<TcpBackend, Error> {
         stream,
         reader,
         writer,
+        parser: TcpFrameParser::new(),
     })
 }
 
-fn new_local_backend(port: &str) -> Result<Box<dyn Backend>, Error> {
+pub(crate) fn new_local_backend(port: &str) -> Result<Box<dyn Backend>, Error> {
     let mut backend: Box<dyn Backend> = if port.starts_with(SERIAL_PREFIX) {
         Box::new(new_serial_backend(
             port.strip_prefix(SERIAL_PREFIX).unwrap_or_default(),
@@ -438,12 +927,171 @@ fn new_remote_backend(address: &str) -> Result<Box<dyn Backend>, Error> {
     Ok(Box::new(new_tcp_backend(address)?))
 }
 
+/// Stands in for `Link::backend` for the instant between closing the old backend and opening its
+/// replacement in `Link::reconnect_backend`, guaranteeing the old handle is actually dropped (and
+/// its device file/port released) before the OS is asked to open the same port again.
+struct NullBackend;
+
+impl Backend for NullBackend {
+    fn reset(&mut self) -> Result<(), Error> {
+        Err(Error::new("Backend is reconnecting"))
+    }
+
+    fn close(&self) {}
+
+    fn read(&mut self, _buffer: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(
+            ErrorKind::NotConnected,
+            "Backend is reconnecting",
+        ))
+    }
+
+    fn write(&mut self, _buffer: &[u8]) -> std::io::Result<()> {
+        Err(std::io::Error::new(
+            ErrorKind::NotConnected,
+            "Backend is reconnecting",
+        ))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Err(std::io::Error::new(
+            ErrorKind::NotConnected,
+            "Backend is reconnecting",
+        ))
+    }
+
+    fn process_incoming_data(
+        &mut self,
+        _data_type: DataType,
+        _packets: &mut VecDeque<Packet>,
+        _resync: bool,
+        _crc: bool,
+    ) -> Result<Option<Response>, Error> {
+        // Tagged with DISCONNECT_MARKER like the other methods' io::Errors get from
+        // classify_io_error: poll_incoming/receive_packet call process_incoming_data directly
+        // (no send_command first), so this is the only error with_reconnect sees on the retry
+        // right after a failed reconnect_backend() — it must still read as disconnect-class or
+        // the retry loop bails out on the first failed re-open instead of working through
+        // max_attempts/backoff.
+        Err(Error::new(
+            format!("{DISCONNECT_MARKER} Backend is reconnecting").as_str(),
+        ))
+    }
+}
+
+/// Remembers how a `Link`'s backend was originally opened, so `Link::set_reconnect` can recreate
+/// it from scratch after a disconnect instead of needing the caller to hold onto the port/address.
+enum Descriptor {
+    Local(String),
+    Remote(String),
+}
+
+impl Descriptor {
+    fn open(&self) -> Result<Box<dyn Backend>, Error> {
+        match self {
+            Self::Local(port) => new_local_backend(port),
+            Self::Remote(address) => new_remote_backend(address),
+        }
+    }
+}
+
+/// Exponential backoff parameters for `Link::set_reconnect`. Only disconnect-class I/O errors
+/// (broken pipe, connection reset, device unplugged) are retried; protocol-level errors such as a
+/// CRC mismatch or a mismatched response ID are always returned to the caller immediately.
+#[derive(Clone, Copy)]
+pub struct ReconnectPolicy {
+    pub max_attempts: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
 pub struct Link {
     pub backend: Box<dyn Backend>,
     packets: VecDeque<Packet>,
+    resync: bool,
+    crc: bool,
+    descriptor: Descriptor,
+    reconnect: Option<ReconnectPolicy>,
 }
 
 impl Link {
+    /// Enables byte-level frame resynchronization instead of failing the link on the first
+    /// corrupted or dropped byte. Useful on noisy USB-serial/FTDI connections; strict callers
+    /// should leave this disabled to keep the fail-fast behavior.
+    pub fn set_resync(&mut self, enabled: bool) {
+        self.resync = enabled;
+    }
+
+    /// Enables CRC32 verification of every command/response/packet payload. This is a manual
+    /// opt-in, not a negotiated capability: there is no handshake with the firmware here, so the
+    /// caller is responsible for confirming the connected firmware actually supports CRC framing
+    /// (e.g. from its reported version) before enabling this, otherwise every exchange will fail.
+    pub fn set_crc(&mut self, enabled: bool) {
+        self.crc = enabled;
+    }
+
+    /// Enables transparent reconnection on disconnect-class I/O errors (broken pipe, connection
+    /// reset, device unplugged): the backend is reopened from the original port/address and the
+    /// in-flight command is retried with exponential backoff. Disabled (`None`) by default, so
+    /// callers get the prior fail-fast behavior unless they opt in.
+    pub fn set_reconnect(&mut self, policy: Option<ReconnectPolicy>) {
+        self.reconnect = policy;
+    }
+
+    fn reconnect_backend(&mut self) -> Result<(), Error> {
+        // Swap in a placeholder and drop the real backend before opening its replacement: some
+        // drivers lock the device exclusively, so a stale handle still held open while re-opening
+        // the same port/address would make the reconnect attempt fail against itself.
+        let old = std::mem::replace(&mut self.backend, Box::new(NullBackend));
+        old.close();
+        drop(old);
+        self.backend = self.descriptor.open()?;
+        Ok(())
+    }
+
+    fn with_reconnect<T>(
+        &mut self,
+        mut operation: impl FnMut(&mut Self) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut attempt = 0;
+        let mut delay = Duration::ZERO;
+        loop {
+            match operation(self) {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    let Some(policy) = self.reconnect else {
+                        return Err(error);
+                    };
+                    if !is_disconnect_error(&error) || attempt >= policy.max_attempts {
+                        return Err(error);
+                    }
+                    delay = if attempt == 0 {
+                        policy.initial_delay
+                    } else {
+                        (delay * 2).min(policy.max_delay)
+                    };
+                    attempt += 1;
+                    std::thread::sleep(delay);
+                    // The device may still be gone; keep consuming attempts/backoff on a failed
+                    // reopen instead of aborting the whole retry loop after a single try.
+                    if self.reconnect_backend().is_err() {
+                        continue;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn execute_command(&mut self, command: &Command) -> Result<Vec<u8>, Error> {
         self.execute_command_raw(command, false, false)
     }
@@ -454,45 +1102,82 @@ impl Link {
         no_response: bool,
         ignore_error: bool,
     ) -> Result<Vec<u8>, Error> {
-        self.backend.send_command(command)?;
-        if no_response {
-            return Ok(vec![]);
-        }
-        let response = self.receive_response()?;
-        if command.id != response.id {
-            return Err(Error::new("Command response ID didn't match"));
-        }
-        if !ignore_error && response.error {
-            return Err(Error::new("Command response error"));
-        }
-        Ok(response.data)
+        self.with_reconnect(|link| {
+            link.backend.send_command(command, link.crc)?;
+            if no_response {
+                return Ok(vec![]);
+            }
+            let response = link.receive_response()?;
+            if command.id != response.id {
+                return Err(Error::new("Command response ID didn't match"));
+            }
+            if !ignore_error && response.error {
+                return Err(Error::new("Command response error"));
+            }
+            Ok(response.data)
+        })
+    }
+
+    /// Sends a command without waiting for its response, retrying through `with_reconnect` on a
+    /// disconnect. Meant for callers that relay traffic asynchronously (`Server`) rather than
+    /// pairing each command with its response the way `execute_command` does.
+    pub(crate) fn send_command_raw(&mut self, command: &Command) -> Result<(), Error> {
+        self.with_reconnect(|link| link.backend.send_command(command, link.crc))
+    }
+
+    /// Polls the backend for whatever response/packet data has arrived so far, without blocking,
+    /// retrying through `with_reconnect` on a disconnect. Meant for callers that relay traffic
+    /// asynchronously (`Server`) rather than calling `receive_response`/`receive_packet` directly.
+    pub(crate) fn poll_incoming(
+        &mut self,
+        data_type: DataType,
+        packets: &mut VecDeque<Packet>,
+    ) -> Result<Option<Response>, Error> {
+        self.with_reconnect(|link| {
+            link.backend
+                .process_incoming_data(data_type, packets, link.resync, link.crc)
+        })
     }
 
     fn receive_response(&mut self) -> Result<Response, Error> {
-        match self
-            .backend
-            .process_incoming_data(DataType::Response, &mut self.packets)
-        {
-            Ok(response) => match response {
-                Some(response) => Ok(response),
-                None => Err(Error::new("No response was received")),
-            },
-            Err(error) => Err(Error::new(
-                format!("Command response error: {error}").as_str(),
-            )),
+        let timeout = Instant::now();
+        loop {
+            match self.backend.process_incoming_data(
+                DataType::Response,
+                &mut self.packets,
+                self.resync,
+                self.crc,
+            ) {
+                Ok(Some(response)) => return Ok(response),
+                Ok(None) => {
+                    if timeout.elapsed() > READ_TIMEOUT {
+                        return Err(Error::new("No response was received"));
+                    }
+                }
+                Err(error) => {
+                    return Err(Error::new(
+                        format!("Command response error: {error}").as_str(),
+                    ))
+                }
+            }
         }
     }
 
     pub fn receive_packet(&mut self) -> Result<Option<Packet>, Error> {
-        if self.packets.len() == 0 {
-            let response = self
-                .backend
-                .process_incoming_data(DataType::Packet, &mut self.packets)?;
-            if response.is_some() {
-                return Err(Error::new("Unexpected command response in data stream"));
+        self.with_reconnect(|link| {
+            if link.packets.len() == 0 {
+                let response = link.backend.process_incoming_data(
+                    DataType::Packet,
+                    &mut link.packets,
+                    link.resync,
+                    link.crc,
+                )?;
+                if response.is_some() {
+                    return Err(Error::new("Unexpected command response in data stream"));
+                }
             }
-        }
-        Ok(self.packets.pop_front())
+            Ok(link.packets.pop_front())
+        })
     }
 }
 
@@ -506,6 +1191,10 @@ pub fn new_local(port: &str) -> Result<Link, Error> {
     Ok(Link {
         backend: new_local_backend(port)?,
         packets: VecDeque::new(),
+        resync: false,
+        crc: false,
+        descriptor: Descriptor::Local(port.to_string()),
+        reconnect: None,
     })
 }
 
@@ -513,6 +1202,10 @@ pub fn new_remote(address: &str) -> Result<Link, Error> {
     Ok(Link {
         backend: new_remote_backend(address)?,
         packets: VecDeque::new(),
+        resync: false,
+        crc: false,
+        descriptor: Descriptor::Remote(address.to_string()),
+        reconnect: None,
     })
 }
 
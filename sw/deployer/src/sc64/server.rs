@@ -0,0 +1,321 @@
+use super::{
+    error::Error,
+    link::{self, Backend, Command, DataType, Link, Packet, ReconnectPolicy, Response},
+};
+use std::{
+    collections::VecDeque,
+    io::{ErrorKind, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+};
+
+/// Guards `read_client_command` against an absurd or corrupted `length` field triggering a huge
+/// allocation; comfortably above the largest ROM/save chunk a client would ever send in one frame.
+const MAX_COMMAND_DATA_LENGTH: usize = 64 * 1024 * 1024;
+
+enum ClientCommandState {
+    WaitDataType,
+    WaitId,
+    WaitArgs { id: u8 },
+    WaitLength { id: u8, args: [u32; 2] },
+    WaitData { id: u8, args: [u32; 2], length: usize },
+    WaitCrc { id: u8, args: [u32; 2], data: Vec<u8> },
+}
+
+/// Resumable parser for `Command` frames arriving from a connected client, buffering partial reads
+/// across `Server::poll()` calls instead of blocking until a whole frame has arrived (mirrors the
+/// `FrameParser`/`TcpFrameParser` state machines `Link` uses on the device side). `crc` mirrors
+/// `TcpBackend::send_command`'s optional trailer over `id || args || data`, since a remote client
+/// is free to call `Link::set_crc(true)` on its end of this exact wire format.
+struct ClientCommandParser {
+    buffer: VecDeque<u8>,
+    state: ClientCommandState,
+    crc: bool,
+}
+
+impl ClientCommandParser {
+    fn new(crc: bool) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            state: ClientCommandState::WaitDataType,
+            crc,
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend(bytes);
+    }
+
+    fn poll(&mut self) -> Result<Option<Command>, Error> {
+        loop {
+            match std::mem::replace(&mut self.state, ClientCommandState::WaitDataType) {
+                ClientCommandState::WaitDataType => {
+                    if self.buffer.len() < 4 {
+                        return Ok(None);
+                    }
+                    let header = [self.buffer[0], self.buffer[1], self.buffer[2], self.buffer[3]];
+                    let data_type: DataType = u32::from_be_bytes(header).try_into()?;
+                    if !matches!(data_type, DataType::Command) {
+                        return Err(Error::new("Expected a command frame from client"));
+                    }
+                    self.buffer.drain(..4);
+                    self.state = ClientCommandState::WaitId;
+                }
+                ClientCommandState::WaitId => {
+                    let Some(id) = self.buffer.pop_front() else {
+                        self.state = ClientCommandState::WaitId;
+                        return Ok(None);
+                    };
+                    self.state = ClientCommandState::WaitArgs { id };
+                }
+                ClientCommandState::WaitArgs { id } => {
+                    if self.buffer.len() < 8 {
+                        self.state = ClientCommandState::WaitArgs { id };
+                        return Ok(None);
+                    }
+                    let bytes: Vec<u8> = self.buffer.drain(..8).collect();
+                    let args = [
+                        u32::from_be_bytes(bytes[0..4].try_into().unwrap()),
+                        u32::from_be_bytes(bytes[4..8].try_into().unwrap()),
+                    ];
+                    self.state = ClientCommandState::WaitLength { id, args };
+                }
+                ClientCommandState::WaitLength { id, args } => {
+                    if self.buffer.len() < 4 {
+                        self.state = ClientCommandState::WaitLength { id, args };
+                        return Ok(None);
+                    }
+                    let bytes: Vec<u8> = self.buffer.drain(..4).collect();
+                    let length = u32::from_be_bytes(bytes.try_into().unwrap()) as usize;
+                    if length > MAX_COMMAND_DATA_LENGTH {
+                        return Err(Error::new(
+                            format!(
+                                "Client command data length {length} exceeds maximum of \
+                                 {MAX_COMMAND_DATA_LENGTH}"
+                            )
+                            .as_str(),
+                        ));
+                    }
+                    self.state = ClientCommandState::WaitData { id, args, length };
+                }
+                ClientCommandState::WaitData { id, args, length } => {
+                    if self.buffer.len() < length {
+                        self.state = ClientCommandState::WaitData { id, args, length };
+                        return Ok(None);
+                    }
+                    let data: Vec<u8> = self.buffer.drain(..length).collect();
+                    if self.crc {
+                        self.state = ClientCommandState::WaitCrc { id, args, data };
+                    } else {
+                        self.state = ClientCommandState::WaitDataType;
+                        return Ok(Some(Command { id, args, data }));
+                    }
+                }
+                ClientCommandState::WaitCrc { id, args, data } => {
+                    if self.buffer.len() < 4 {
+                        self.state = ClientCommandState::WaitCrc { id, args, data };
+                        return Ok(None);
+                    }
+                    let bytes: Vec<u8> = self.buffer.drain(..4).collect();
+                    let received = u32::from_be_bytes(bytes.try_into().unwrap());
+                    self.state = ClientCommandState::WaitDataType;
+                    let expected = link::crc32(&[
+                        &id.to_be_bytes(),
+                        &args[0].to_be_bytes(),
+                        &args[1].to_be_bytes(),
+                        &data,
+                    ]);
+                    if received != expected {
+                        return Err(Error::new("CRC32 checksum mismatch"));
+                    }
+                    return Ok(Some(Command { id, args, data }));
+                }
+            }
+        }
+    }
+}
+
+/// Serves a locally attached SC64 over TCP, relaying `Command`/`Response`/`Packet` frames to and
+/// from a single connected client using the same `DataType`-prefixed wire format `TcpBackend`
+/// speaks on the client side. Turns `new_remote`'s one-directional link into a `sc64 --listen
+/// <addr>` style deployment where the cart stays attached to this machine.
+///
+/// There are two independent legs here, each with its own resilience settings: the device side is
+/// a regular `Link`, so a flaky USB connection to the local device gets the same resync/CRC/
+/// reconnect resilience a direct `sc64` session would (`set_resync`/`set_crc`/`set_reconnect`);
+/// the client side is a plain TCP socket to a remote `Link`, which only supports an optional CRC32
+/// trailer on that same wire format (`set_client_crc`) - it must be set to match whatever the
+/// connecting client passed to its own `set_crc`, or every frame fails to parse.
+pub struct Server {
+    link: Link,
+    listener: TcpListener,
+    client: Option<TcpStream>,
+    client_crc: bool,
+    parser: ClientCommandParser,
+}
+
+impl Server {
+    pub fn new(port: &str, address: &str) -> Result<Self, Error> {
+        let link = link::new_local(port)?;
+
+        let listener = TcpListener::bind(address).map_err(|error| {
+            Error::new(format!("Couldn't listen on [{address}]: {error}").as_str())
+        })?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            link,
+            listener,
+            client: None,
+            client_crc: false,
+            parser: ClientCommandParser::new(false),
+        })
+    }
+
+    /// See `Link::set_resync`. Applies to the device-side connection only.
+    pub fn set_resync(&mut self, enabled: bool) {
+        self.link.set_resync(enabled);
+    }
+
+    /// See `Link::set_crc`. Applies to the device-side connection only; see `set_client_crc` for
+    /// the client-facing leg.
+    pub fn set_crc(&mut self, enabled: bool) {
+        self.link.set_crc(enabled);
+    }
+
+    /// Enables CRC32 verification of `Command`/`Response`/`Packet` frames exchanged with the
+    /// connected client, matching the trailer `TcpBackend::send_command` appends when a remote
+    /// `Link` has `set_crc(true)` called on it. Must match what the client is actually doing:
+    /// since there is no negotiation, a mismatch fails every frame on whichever side expects it.
+    pub fn set_client_crc(&mut self, enabled: bool) {
+        self.client_crc = enabled;
+        self.parser.crc = enabled;
+    }
+
+    /// See `Link::set_reconnect`. Applies to the device-side connection only.
+    pub fn set_reconnect(&mut self, policy: Option<ReconnectPolicy>) {
+        self.link.set_reconnect(policy);
+    }
+
+    /// Accepts a waiting client and relays one round of traffic in both directions. Meant to be
+    /// called repeatedly from a poll loop; never waits for a client to connect or for a whole frame
+    /// to arrive, but with `set_reconnect` enabled a device disconnect makes it sleep through the
+    /// reconnect backoff before returning.
+    pub fn poll(&mut self) -> Result<(), Error> {
+        self.accept_client()?;
+        if self.client.is_some() {
+            if let Err(error) = self.service() {
+                self.disconnect_client();
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn close(&mut self) {
+        self.disconnect_client();
+        self.link.backend.close();
+    }
+
+    fn service(&mut self) -> Result<(), Error> {
+        self.forward_client_command()?;
+        self.forward_device_data()?;
+        Ok(())
+    }
+
+    fn accept_client(&mut self) -> Result<(), Error> {
+        match self.listener.accept() {
+            Ok((stream, _)) => {
+                stream.set_nonblocking(true)?;
+                stream.set_nodelay(true).ok();
+                self.disconnect_client();
+                self.client = Some(stream);
+                self.parser = ClientCommandParser::new(self.client_crc);
+                Ok(())
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn disconnect_client(&mut self) {
+        if let Some(client) = self.client.take() {
+            client.shutdown(Shutdown::Both).ok();
+        }
+    }
+
+    fn forward_client_command(&mut self) -> Result<(), Error> {
+        let Some(command) = self.read_client_command()? else {
+            return Ok(());
+        };
+        self.link.send_command_raw(&command)
+    }
+
+    fn read_client_command(&mut self) -> Result<Option<Command>, Error> {
+        let client = self.client.as_mut().unwrap();
+        let bytes = Self::poll_available(client)?;
+        self.parser.feed(&bytes);
+        self.parser.poll()
+    }
+
+    /// Drains whatever bytes the client has sent so far without blocking, mirroring
+    /// `Backend::poll_available` on the device side.
+    fn poll_available(client: &mut TcpStream) -> Result<Vec<u8>, Error> {
+        let mut collected = Vec::new();
+        loop {
+            let mut chunk = [0u8; 512];
+            match client.read(&mut chunk) {
+                Ok(0) => return Err(Error::new("Client disconnected")),
+                Ok(bytes) => collected.extend_from_slice(&chunk[..bytes]),
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Ok(collected)
+    }
+
+    fn forward_device_data(&mut self) -> Result<(), Error> {
+        let mut packets = VecDeque::new();
+        let response = self.link.poll_incoming(DataType::Packet, &mut packets)?;
+
+        if let Some(response) = response {
+            self.write_client_response(&response)?;
+        }
+        for packet in packets {
+            self.write_client_packet(&packet)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_client_response(&mut self, response: &Response) -> Result<(), Error> {
+        let client = self.client.as_mut().unwrap();
+        let data_type: u32 = DataType::Response.into();
+        client.write_all(&data_type.to_be_bytes())?;
+        client.write_all(&[response.id, response.error as u8])?;
+        client.write_all(&(response.data.len() as u32).to_be_bytes())?;
+        client.write_all(&response.data)?;
+        if self.client_crc {
+            let checksum = link::crc32(&[&[response.id], &response.data]);
+            client.write_all(&checksum.to_be_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn write_client_packet(&mut self, packet: &Packet) -> Result<(), Error> {
+        let client = self.client.as_mut().unwrap();
+        let data_type: u32 = DataType::Packet.into();
+        client.write_all(&data_type.to_be_bytes())?;
+        client.write_all(&[packet.id])?;
+        client.write_all(&(packet.data.len() as u32).to_be_bytes())?;
+        client.write_all(&packet.data)?;
+        if self.client_crc {
+            let checksum = link::crc32(&[&[packet.id], &packet.data]);
+            client.write_all(&checksum.to_be_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+pub fn listen(port: &str, address: &str) -> Result<Server, Error> {
+    Server::new(port, address)
+}